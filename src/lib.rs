@@ -1,31 +1,213 @@
 use napi_derive::napi;
 use once_cell::sync::OnceCell;
 use owo_colors::OwoColorize;
+use std::io::IsTerminal;
 use time::macros::format_description;
 use tracing::Level;
+use tracing_appender::rolling::Rotation;
 use tracing_subscriber::{
-    fmt::{format::Writer, FmtContext, FormatEvent, FormatFields},
+    fmt::{format::FmtSpan, format::Writer, FmtContext, FormatEvent, FormatFields},
+    layer::Context as LayerContext,
     prelude::*,
     registry::LookupSpan,
-    EnvFilter,
+    reload,
+    EnvFilter, Layer,
 };
 
+/// Parses the JSON blob carried by a `fields` field back into `key=value` pairs,
+/// space separated, for the colored formatter. Returns `None` when there are no
+/// fields to show.
+fn format_fields_text(json: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(json).ok()?;
+    let map = value.as_object()?;
+    if map.is_empty() {
+        return None;
+    }
+    Some(
+        map.iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
 static LOGGER_INIT: OnceCell<()> = OnceCell::new();
+// Keeps the non-blocking file writer's worker thread alive for the process
+// lifetime; dropping it would silently stop flushing buffered lines.
+static FILE_GUARD: OnceCell<tracing_appender::non_blocking::WorkerGuard> = OnceCell::new();
+// Lets `Logger::set_filter` change verbosity at runtime without rebuilding the
+// (process-global, set-once) subscriber.
+static FILTER_HANDLE: OnceCell<reload::Handle<EnvFilter, tracing_subscriber::Registry>> =
+    OnceCell::new();
+
+/// Resolves a `spanEvents` option value to the span lifecycle events that should be
+/// logged.
+fn resolve_span_events(mode: &str) -> FmtSpan {
+    match mode {
+        "close" => FmtSpan::CLOSE,
+        "full" => FmtSpan::FULL,
+        _ => FmtSpan::NONE,
+    }
+}
 
 /// Configuration options for the logger
 /// @param {string} [level] - Log level (trace, debug, info, warn, error). Defaults to "info"
-/// @param {boolean} [json] - Whether to output logs in JSON format. Defaults to false
+/// @param {boolean} [json] - When true, emit one JSON object per line instead of the
+///   colored human-readable format. Useful for shipping logs to aggregators that parse
+///   structured output. Defaults to false
+/// @param {string} [color] - Controls ANSI color output: "auto" detects whether stdout
+///   is a terminal, "always" forces colors, "never" disables them. Defaults to "auto"
+/// @param {FileOptions} [file] - When set, also persist logs to a rolling file
+/// @param {string} [spanEvents] - Logs a line when spans opened via `Logger.span`
+///   close, including their busy/idle duration: "none" (default), "close", or "full"
+///   (also logs span creation/entry/exit)
 #[napi(object)]
 pub struct LoggerOptions {
     pub level: Option<String>,
     pub json: Option<bool>,
+    pub color: Option<String>,
+    pub file: Option<FileOptions>,
+    pub span_events: Option<String>,
+}
+
+/// Configuration for persisting logs to a file in addition to stdout.
+/// @param {string} path - Base file path; the rolling appender appends a date/hour
+///   suffix to the file name unless `rotation` is "never"
+/// @param {string} [rotation] - "daily" (default), "hourly", or "never" for a single file
+#[napi(object)]
+pub struct FileOptions {
+    pub path: String,
+    pub rotation: Option<String>,
+}
+
+/// Resolves a `color` option value to whether ANSI colors should be emitted.
+fn resolve_color(mode: &str) -> bool {
+    match mode {
+        "always" => true,
+        "never" => false,
+        _ => std::io::stdout().is_terminal(),
+    }
 }
 
 #[napi]
 pub struct Logger {}
 
+/// The human-readable name of a span, captured from its `label` field so it can be
+/// looked up again when rendering child events.
+struct SpanLabel(String);
+
+/// Stashes each span's `label` field in its extensions on creation, so
+/// `CustomFormat` can print a `parent>child` breadcrumb without re-visiting fields.
+struct SpanLabelLayer;
+
+impl<S> Layer<S> for SpanLabelLayer
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(
+        &self,
+        attrs: &tracing::span::Attributes<'_>,
+        id: &tracing::span::Id,
+        ctx: LayerContext<'_, S>,
+    ) {
+        struct LabelVisitor(Option<String>);
+
+        impl tracing::field::Visit for LabelVisitor {
+            fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+                if field.name() == "label" {
+                    self.0 = Some(value.to_string());
+                }
+            }
+
+            fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "label" {
+                    self.0 = Some(format!("{value:?}"));
+                }
+            }
+        }
+
+        let mut visitor = LabelVisitor(None);
+        attrs.record(&mut visitor);
+
+        if let (Some(label), Some(span)) = (visitor.0, ctx.span(id)) {
+            span.extensions_mut().insert(SpanLabel(label));
+        }
+    }
+}
+
+/// The subscriber stack every fmt layer (stdout, file, ...) is applied on top of.
+type BaseSubscriber = tracing_subscriber::layer::Layered<
+    SpanLabelLayer,
+    tracing_subscriber::layer::Layered<
+        reload::Layer<EnvFilter, tracing_subscriber::Registry>,
+        tracing_subscriber::Registry,
+    >,
+>;
+
 struct CustomFormat {
     service_name: String,
+    color: bool,
+}
+
+/// Writes an event's fields as space-separated text: the message bare, a `fields`
+/// blob expanded into `key=value` pairs, and anything else as `name=value`.
+struct CustomFieldVisitor<'a, 'b> {
+    writer: &'a mut Writer<'b>,
+    first: bool,
+    result: std::fmt::Result,
+}
+
+impl<'a, 'b> CustomFieldVisitor<'a, 'b> {
+    fn write_part(&mut self, text: impl std::fmt::Display) {
+        if self.result.is_err() {
+            return;
+        }
+        self.result = (|| {
+            if !self.first {
+                write!(self.writer, " ")?;
+            }
+            write!(self.writer, "{text}")
+        })();
+        self.first = false;
+    }
+
+    fn write_field(&mut self, field: &tracing::field::Field, value: impl std::fmt::Display) {
+        match field.name() {
+            "message" => self.write_part(value),
+            "fields" => {
+                if let Some(text) = format_fields_text(&value.to_string()) {
+                    self.write_part(text);
+                }
+            }
+            name => self.write_part(format!("{name}={value}")),
+        }
+    }
+}
+
+impl<'a, 'b> tracing::field::Visit for CustomFieldVisitor<'a, 'b> {
+    fn record_f64(&mut self, field: &tracing::field::Field, value: f64) {
+        self.write_field(field, value);
+    }
+
+    fn record_i64(&mut self, field: &tracing::field::Field, value: i64) {
+        self.write_field(field, value);
+    }
+
+    fn record_u64(&mut self, field: &tracing::field::Field, value: u64) {
+        self.write_field(field, value);
+    }
+
+    fn record_bool(&mut self, field: &tracing::field::Field, value: bool) {
+        self.write_field(field, value);
+    }
+
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.write_field(field, value);
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.write_field(field, format!("{value:?}"));
+    }
 }
 
 impl<S, N> FormatEvent<S, N> for CustomFormat
@@ -47,43 +229,217 @@ where
             .unwrap();
 
         let level = event.metadata().level();
+        let level_str = match *level {
+            Level::ERROR => "ERROR",
+            Level::WARN => "WARN",
+            Level::INFO => "INFO",
+            Level::DEBUG => "DEBUG",
+            Level::TRACE => "TRACE",
+        };
+
+        if self.color {
+            write!(
+                writer,
+                "{}{}{}",
+                "[".bright_black(),
+                date.bright_blue(),
+                "]".bright_black()
+            )?;
 
-        write!(
-            writer,
-            "{}{}{}",
-            "[".bright_black(),
-            date.bright_blue(),
-            "]".bright_black()
-        )?;
-
-        write!(
-            writer,
-            "{}{}{}",
-            "(".bright_black(),
-            self.service_name.bright_magenta(),
-            ")".bright_black()
-        )?;
-
-        write!(
-            writer,
-            "{}{}{}",
-            "[".bright_black(),
-            match *level {
-                Level::ERROR => "ERROR".to_string().red().to_string(),
-                Level::WARN => "WARN".to_string().yellow().to_string(),
-                Level::INFO => "INFO".to_string().cyan().to_string(),
-                Level::DEBUG => "DEBUG".to_string().green().to_string(),
-                Level::TRACE => "TRACE".to_string().purple().to_string(),
-            },
-            "]".bright_black()
-        )?;
+            write!(
+                writer,
+                "{}{}{}",
+                "(".bright_black(),
+                self.service_name.bright_magenta(),
+                ")".bright_black()
+            )?;
+
+            write!(
+                writer,
+                "{}{}{}",
+                "[".bright_black(),
+                match *level {
+                    Level::ERROR => level_str.red().to_string(),
+                    Level::WARN => level_str.yellow().to_string(),
+                    Level::INFO => level_str.cyan().to_string(),
+                    Level::DEBUG => level_str.green().to_string(),
+                    Level::TRACE => level_str.purple().to_string(),
+                },
+                "]".bright_black()
+            )?;
+        } else {
+            write!(writer, "[{date}]")?;
+            write!(writer, "({})", self.service_name)?;
+            write!(writer, "[{level_str}]")?;
+        }
 
         writer.write_char(' ')?;
-        ctx.field_format().format_fields(writer.by_ref(), event)?;
+
+        if let Some(scope) = ctx.event_scope() {
+            let chain = scope
+                .from_root()
+                .map(|span| {
+                    span.extensions()
+                        .get::<SpanLabel>()
+                        .map(|label| label.0.clone())
+                        .unwrap_or_else(|| span.name().to_string())
+                })
+                .collect::<Vec<_>>()
+                .join(">");
+
+            if !chain.is_empty() {
+                if self.color {
+                    write!(writer, "{} ", chain.bright_black())?;
+                } else {
+                    write!(writer, "{chain} ")?;
+                }
+            }
+        }
+
+        let mut visitor = CustomFieldVisitor {
+            writer: &mut writer,
+            first: true,
+            result: Ok(()),
+        };
+        event.record(&mut visitor);
+        visitor.result?;
+
         writeln!(writer)
     }
 }
 
+struct JsonFormat {
+    service_name: String,
+}
+
+/// The top-level keys `JsonFormat` fills in itself (`span` only for events inside a
+/// span, `message` from the event's own message), plus `fields`, which carries the
+/// structured fields; any other field of the same name is dropped rather than
+/// allowed to shadow one of these.
+const RESERVED_JSON_KEYS: [&str; 6] = [
+    "timestamp",
+    "level",
+    "service_name",
+    "span",
+    "message",
+    "fields",
+];
+
+/// Records a tracing event's fields as entries in a `serde_json::Map`, so the whole
+/// line is serialized once through `serde_json` instead of hand-escaping strings.
+struct JsonFieldVisitor {
+    map: serde_json::Map<String, serde_json::Value>,
+}
+
+impl JsonFieldVisitor {
+    fn insert(&mut self, name: &str, value: serde_json::Value) {
+        if RESERVED_JSON_KEYS.contains(&name) {
+            return;
+        }
+        self.map.insert(name.to_string(), value);
+    }
+
+    fn record_string(&mut self, field: &tracing::field::Field, value: &str) {
+        // The event's own message is a reserved key, so it is set directly rather
+        // than through `insert`.
+        if field.name() == "message" {
+            self.map
+                .insert("message".to_string(), serde_json::Value::String(value.to_string()));
+            return;
+        }
+
+        // The `fields` field carries an already-serialized JSON object; flatten its
+        // entries into the top-level event object (skipping any that collide with a
+        // reserved key) so structured fields stay queryable as first-class JSON keys.
+        if field.name() == "fields" {
+            if let Ok(serde_json::Value::Object(fields)) = serde_json::from_str(value) {
+                for (key, val) in fields {
+                    self.insert(&key, val);
+                }
+            }
+            return;
+        }
+
+        self.insert(field.name(), serde_json::Value::String(value.to_string()));
+    }
+}
+
+impl tracing::field::Visit for JsonFieldVisitor {
+    fn record_f64(&mut self, field: &tracing::field::Field, value: f64) {
+        self.insert(field.name(), serde_json::json!(value));
+    }
+
+    fn record_i64(&mut self, field: &tracing::field::Field, value: i64) {
+        self.insert(field.name(), serde_json::json!(value));
+    }
+
+    fn record_u64(&mut self, field: &tracing::field::Field, value: u64) {
+        self.insert(field.name(), serde_json::json!(value));
+    }
+
+    fn record_bool(&mut self, field: &tracing::field::Field, value: bool) {
+        self.insert(field.name(), serde_json::json!(value));
+    }
+
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.record_string(field, value);
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.record_string(field, &format!("{value:?}"));
+    }
+}
+
+impl<S, N> FormatEvent<S, N> for JsonFormat
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &tracing::Event<'_>,
+    ) -> std::fmt::Result {
+        let timestamp = time::OffsetDateTime::now_utc()
+            .format(&time::format_description::well_known::Rfc3339)
+            .map_err(|_| std::fmt::Error)?;
+        let level = event.metadata().level();
+
+        let mut map = serde_json::Map::new();
+        map.insert("timestamp".to_string(), serde_json::json!(timestamp));
+        map.insert("level".to_string(), serde_json::json!(level.to_string()));
+        map.insert(
+            "service_name".to_string(),
+            serde_json::json!(self.service_name),
+        );
+
+        if let Some(scope) = ctx.event_scope() {
+            let chain = scope
+                .from_root()
+                .map(|span| {
+                    span.extensions()
+                        .get::<SpanLabel>()
+                        .map(|label| label.0.clone())
+                        .unwrap_or_else(|| span.name().to_string())
+                })
+                .collect::<Vec<_>>()
+                .join(">");
+
+            if !chain.is_empty() {
+                map.insert("span".to_string(), serde_json::json!(chain));
+            }
+        }
+
+        let mut visitor = JsonFieldVisitor { map };
+        event.record(&mut visitor);
+
+        let line = serde_json::to_string(&serde_json::Value::Object(visitor.map))
+            .map_err(|_| std::fmt::Error)?;
+        writeln!(writer, "{line}")
+    }
+}
+
 #[napi]
 impl Logger {
     /// Creates a new logger instance
@@ -97,23 +453,93 @@ impl Logger {
             let opts = options.unwrap_or(LoggerOptions {
                 level: Some("info".to_string()),
                 json: Some(false),
+                color: Some("auto".to_string()),
+                file: None,
+                span_events: Some("none".to_string()),
             });
 
             let level = opts.level.unwrap_or_else(|| "info".to_string());
+            let json = opts.json.unwrap_or(false);
+            let color = resolve_color(opts.color.as_deref().unwrap_or("auto"));
+            let span_events = resolve_span_events(opts.span_events.as_deref().unwrap_or("none"));
 
-            let format = CustomFormat {
-                service_name: name.clone(),
-            };
-
-            let filter_layer = EnvFilter::try_from_default_env()
+            let filter = EnvFilter::try_from_default_env()
                 .or_else(|_| EnvFilter::try_new(&level))
                 .unwrap();
+            let (filter_layer, filter_handle) = reload::Layer::new(filter);
+            FILTER_HANDLE.set(filter_handle).ok();
+
+            let mut layers: Vec<Box<dyn Layer<BaseSubscriber> + Send + Sync>> = Vec::new();
+
+            if json {
+                layers.push(Box::new(
+                    tracing_subscriber::fmt::layer()
+                        .with_span_events(span_events.clone())
+                        .event_format(JsonFormat {
+                            service_name: name.clone(),
+                        }),
+                ));
+            } else {
+                layers.push(Box::new(
+                    tracing_subscriber::fmt::layer()
+                        .with_span_events(span_events.clone())
+                        .event_format(CustomFormat {
+                            service_name: name.clone(),
+                            color,
+                        }),
+                ));
+            }
+
+            if let Some(file_opts) = opts.file {
+                let rotation = match file_opts.rotation.as_deref() {
+                    Some("hourly") => Rotation::HOURLY,
+                    Some("never") => Rotation::NEVER,
+                    _ => Rotation::DAILY,
+                };
 
-            let fmt_layer = tracing_subscriber::fmt::layer().event_format(format);
+                let path = std::path::Path::new(&file_opts.path);
+                let directory = path
+                    .parent()
+                    .filter(|dir| !dir.as_os_str().is_empty())
+                    .unwrap_or_else(|| std::path::Path::new("."));
+                let file_name = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .unwrap_or("app.log");
+
+                let appender =
+                    tracing_appender::rolling::RollingFileAppender::new(rotation, directory, file_name);
+                let (non_blocking, guard) = tracing_appender::non_blocking(appender);
+                FILE_GUARD.set(guard).ok();
+
+                // File output is always plain text, regardless of the `color` setting.
+                let file_layer: Box<dyn Layer<BaseSubscriber> + Send + Sync> = if json {
+                    Box::new(
+                        tracing_subscriber::fmt::layer()
+                            .with_span_events(span_events)
+                            .event_format(JsonFormat {
+                                service_name: name.clone(),
+                            })
+                            .with_writer(non_blocking),
+                    )
+                } else {
+                    Box::new(
+                        tracing_subscriber::fmt::layer()
+                            .with_span_events(span_events)
+                            .event_format(CustomFormat {
+                                service_name: name.clone(),
+                                color: false,
+                            })
+                            .with_writer(non_blocking),
+                    )
+                };
+                layers.push(file_layer);
+            }
 
             let subscriber = tracing_subscriber::registry()
                 .with(filter_layer)
-                .with(fmt_layer);
+                .with(SpanLabelLayer)
+                .with(layers);
 
             tracing::subscriber::set_global_default(subscriber)
                 .expect("Failed to set tracing subscriber");
@@ -122,31 +548,155 @@ impl Logger {
         Self {}
     }
 
+    /// Changes the active log filter directives at runtime, without restarting the
+    /// process or constructing a new `Logger`.
+    /// @param {string} directives - An `EnvFilter`-style directive string, e.g.
+    ///   `"info,mymod=trace"`
+    #[napi]
+    pub fn set_filter(&self, directives: String) -> napi::Result<()> {
+        let filter = EnvFilter::try_new(&directives)
+            .map_err(|e| napi::Error::from_reason(format!("invalid filter directives: {e}")))?;
+        let handle = FILTER_HANDLE
+            .get()
+            .ok_or_else(|| napi::Error::from_reason("logger is not initialized"))?;
+        handle
+            .reload(filter)
+            .map_err(|e| napi::Error::from_reason(format!("failed to reload filter: {e}")))
+    }
+
+    /// Opens a tracing span that gives log lines hierarchical context once it is
+    /// entered with `Span.enter()`.
+    /// @param {string} name - The span's name, shown as a `parent>child` breadcrumb
+    /// @param {object} [fields] - Structured fields to attach to the span
+    /// @returns {Span} A handle controlling the span's lifetime
+    #[napi]
+    pub fn span(&self, name: String, fields: Option<serde_json::Value>) -> Span {
+        let fields_json = fields_to_json(fields);
+        let span = tracing::span!(Level::INFO, "span", label = %name, fields = %fields_json);
+        Span {
+            span: Some(span),
+            guard: None,
+        }
+    }
+
     /// Logs a debug message
     /// @param {string} message - The message to log at debug level
+    /// @param {object} [fields] - Structured fields to record alongside the message
     #[napi]
-    pub fn debug(&self, message: String) {
-        tracing::debug!("{}", message);
+    pub fn debug(&self, message: String, fields: Option<serde_json::Value>) {
+        let fields_json = fields_to_json(fields);
+        tracing::debug!(fields = %fields_json, "{}", message);
     }
 
     /// Logs an info message
     /// @param {string} message - The message to log at info level
+    /// @param {object} [fields] - Structured fields to record alongside the message
     #[napi]
-    pub fn info(&self, message: String) {
-        tracing::info!("{}", message);
+    pub fn info(&self, message: String, fields: Option<serde_json::Value>) {
+        let fields_json = fields_to_json(fields);
+        tracing::info!(fields = %fields_json, "{}", message);
     }
 
     /// Logs a warning message
     /// @param {string} message - The message to log at warn level
+    /// @param {object} [fields] - Structured fields to record alongside the message
     #[napi]
-    pub fn warn(&self, message: String) {
-        tracing::warn!("{}", message);
+    pub fn warn(&self, message: String, fields: Option<serde_json::Value>) {
+        let fields_json = fields_to_json(fields);
+        tracing::warn!(fields = %fields_json, "{}", message);
     }
 
     /// Logs an error message
     /// @param {string} message - The message to log at error level
+    /// @param {object} [fields] - Structured fields to record alongside the message
     #[napi]
-    pub fn error(&self, message: String) {
-        tracing::error!("{}", message);
+    pub fn error(&self, message: String, fields: Option<serde_json::Value>) {
+        let fields_json = fields_to_json(fields);
+        tracing::error!(fields = %fields_json, "{}", message);
+    }
+}
+
+/// Serializes a JS fields object to a JSON string, so it can be carried as a single
+/// field on a span or event and read back by the formatters.
+fn fields_to_json(fields: Option<serde_json::Value>) -> String {
+    fields
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| "{}".to_string())
+}
+
+/// A handle to a tracing span opened via `Logger::span`, controlling when it is
+/// entered and exited from JS.
+#[napi]
+pub struct Span {
+    span: Option<tracing::Span>,
+    guard: Option<tracing::span::EnteredSpan>,
+}
+
+#[napi]
+impl Span {
+    /// Enters the span, making it the current context for subsequent log calls
+    /// until `close()` is called.
+    #[napi]
+    pub fn enter(&mut self) {
+        if self.guard.is_some() {
+            return;
+        }
+        if let Some(span) = self.span.take() {
+            self.guard = Some(span.entered());
+        }
+    }
+
+    /// Exits the span and drops it, ending its hierarchical context and firing the
+    /// `close` span event (with its busy/idle duration) deterministically, rather
+    /// than whenever this handle happens to be garbage collected.
+    #[napi]
+    pub fn close(&mut self) {
+        if let Some(guard) = self.guard.take() {
+            drop(guard.exit());
+        }
+        self.span.take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_field_visitor_insert_drops_reserved_keys() {
+        let mut visitor = JsonFieldVisitor {
+            map: serde_json::Map::new(),
+        };
+        visitor.insert("user_id", serde_json::json!(1));
+        visitor.insert("message", serde_json::json!("should be dropped"));
+        assert_eq!(visitor.map.get("user_id"), Some(&serde_json::json!(1)));
+        assert_eq!(visitor.map.get("message"), None);
+    }
+
+    #[test]
+    fn format_fields_text_formats_nonempty_object() {
+        let text = format_fields_text(r#"{"user_id":1,"status":"ok"}"#).unwrap();
+        assert!(text.contains("user_id=1"));
+        assert!(text.contains("status=\"ok\""));
+    }
+
+    #[test]
+    fn format_fields_text_is_none_for_empty_or_invalid() {
+        assert_eq!(format_fields_text("{}"), None);
+        assert_eq!(format_fields_text("not json"), None);
+    }
+
+    #[test]
+    fn resolve_color_respects_explicit_modes() {
+        assert!(resolve_color("always"));
+        assert!(!resolve_color("never"));
+    }
+
+    #[test]
+    fn resolve_span_events_maps_known_modes() {
+        assert_eq!(resolve_span_events("none"), FmtSpan::NONE);
+        assert_eq!(resolve_span_events("close"), FmtSpan::CLOSE);
+        assert_eq!(resolve_span_events("full"), FmtSpan::FULL);
+        assert_eq!(resolve_span_events("unknown"), FmtSpan::NONE);
     }
 }